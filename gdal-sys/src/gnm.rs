@@ -0,0 +1,77 @@
+use std::os::raw::{c_char, c_double, c_long};
+
+use crate::{GDALDatasetH, OGRErr, OGRLayerH};
+
+/// Handle to a GNM (Geographic Network Model) network.
+///
+/// A GNM network is itself a [`GDALDatasetH`]: layers created on it become network
+/// classes, and the features created in those layers become the network's nodes/edges.
+pub type GNMNetworkH = GDALDatasetH;
+
+/// A network-global feature id, as assigned by GNM when a feature's layer is registered
+/// as a network class.
+pub type GNMGFID = c_long;
+
+pub mod GNMNetworkType {
+    pub type Type = u32;
+    pub const GNM_NETWORK_GENERIC: Type = 0;
+}
+
+pub mod GNMDirection {
+    pub type Type = u32;
+    pub const GNM_EDGE_DIR_SINGLE: Type = 1;
+    pub const GNM_EDGE_DIR_DOUBLE: Type = 2;
+}
+
+pub mod GNMGraphAlgorithm {
+    pub type Type = u32;
+    pub const GATDijkstraShortestPath: Type = 1;
+    pub const GATKShortestPath: Type = 2;
+    pub const GATConnectedComponents: Type = 3;
+}
+
+extern "C" {
+    pub fn GNMCreateNetwork(
+        hDS: GDALDatasetH,
+        pszPath: *const c_char,
+        pszName: *const c_char,
+        pszSRSWKT: *const c_char,
+        eType: GNMNetworkType::Type,
+        papszOptions: *mut *mut c_char,
+    ) -> GNMNetworkH;
+
+    pub fn GNMOpenNetwork(hDS: GDALDatasetH) -> GNMNetworkH;
+
+    pub fn GNMCloseNetwork(hNet: GNMNetworkH) -> OGRErr::Type;
+
+    pub fn GNMConnectPointsByFeaturesIDs(
+        hNet: GNMNetworkH,
+        nSrcFID: GNMGFID,
+        nTgtFID: GNMGFID,
+        nConnectorFID: GNMGFID,
+        dfCost: c_double,
+        dfInvCost: c_double,
+        eDir: GNMDirection::Type,
+    ) -> GNMGFID;
+
+    pub fn GNMDisconnectFeaturesIDs(
+        hNet: GNMNetworkH,
+        nSrcFID: GNMGFID,
+        nTgtFID: GNMGFID,
+        nConnectorFID: GNMGFID,
+    ) -> OGRErr::Type;
+
+    pub fn GNMGenericNetworkLoadGraph(hNet: GNMNetworkH) -> OGRErr::Type;
+
+    pub fn GNMGenericNetworkGetPath(
+        hNet: GNMNetworkH,
+        nStartFID: GNMGFID,
+        nEndFID: GNMGFID,
+        eAlgorithm: GNMGraphAlgorithm::Type,
+        papszOptions: *mut *mut c_char,
+    ) -> OGRLayerH;
+
+    pub fn GNMGenericNetworkReleaseResultSet(hNet: GNMNetworkH, hLayer: OGRLayerH);
+
+    pub fn GNMGetName(hNet: GNMNetworkH) -> *const c_char;
+}
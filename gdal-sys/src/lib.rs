@@ -0,0 +1,10 @@
+#![allow(non_camel_case_types, non_snake_case, non_upper_case_globals)]
+
+// Bindgen-generated bindings for the GDAL/OGR/CPL C API (GDALDatasetH, OGRLayerH,
+// OGRErr, CPLErr, OGRFieldType, OGRwkbGeometryType, and friends all come from here).
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+// GNM (Geographic Network Model) isn't covered by the bindgen allowlist, so its bindings
+// are hand-written instead of generated.
+mod gnm;
+pub use gnm::*;
@@ -0,0 +1,311 @@
+use std::ffi::CString;
+use std::ops::Deref;
+use std::ptr;
+
+use gdal_sys::{self, CPLErr, GNMNetworkH, OGRLayerH, OGRwkbGeometryType};
+
+use crate::dataset::Dataset;
+use crate::errors::*;
+use crate::utils::{_last_cpl_err, _last_null_pointer_err, _path_to_c_string, _string};
+use crate::vector::Layer;
+
+/// A Geographic Network Model (GNM) built on top of a [`Dataset`]'s vector layers.
+///
+/// GNM turns a set of OGR layers into a routable graph: layers (or individual features)
+/// are connected into edges, the topology is loaded into memory, and the network can then
+/// be queried for shortest paths or connected components. Query results are returned as
+/// ordinary vector [`Layer`]s, so they can be iterated with the crate's existing
+/// [`FieldIterator`](crate::vector::FieldIterator) machinery like any other layer.
+#[derive(Debug)]
+pub struct Network {
+    c_network: GNMNetworkH,
+}
+
+impl Network {
+    /// Creates a new generic network in `path`, using `dataset` as the source of the
+    /// layers that will become the network's nodes and edges.
+    ///
+    /// This wraps `GNMCreateNetwork`.
+    pub fn create<P: AsRef<std::path::Path>>(
+        dataset: &Dataset,
+        path: P,
+        name: &str,
+        srs_wkt: &str,
+    ) -> Result<Network> {
+        let c_path = _path_to_c_string(path.as_ref())?;
+        let c_name = CString::new(name)?;
+        let c_srs_wkt = CString::new(srs_wkt)?;
+        let c_network = unsafe {
+            gdal_sys::GNMCreateNetwork(
+                dataset.c_dataset(),
+                c_path.as_ptr(),
+                c_name.as_ptr(),
+                c_srs_wkt.as_ptr(),
+                gdal_sys::GNMNetworkType::GNM_NETWORK_GENERIC,
+                ptr::null_mut(),
+            )
+        };
+        if c_network.is_null() {
+            return Err(_last_null_pointer_err("GNMCreateNetwork"));
+        }
+        Ok(Network { c_network })
+    }
+
+    /// Opens the generic network already stored alongside `dataset`.
+    ///
+    /// This wraps `GNMOpenNetwork`.
+    pub fn open(dataset: &Dataset) -> Result<Network> {
+        let c_network = unsafe { gdal_sys::GNMOpenNetwork(dataset.c_dataset()) };
+        if c_network.is_null() {
+            return Err(_last_null_pointer_err("GNMOpenNetwork"));
+        }
+        Ok(Network { c_network })
+    }
+
+    /// Returns the wrapped C pointer.
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_network(&self) -> GNMNetworkH {
+        self.c_network
+    }
+
+    /// Creates a new layer of `geom_type` directly on the network, registering it as a
+    /// network class.
+    ///
+    /// A GNM network is itself a vector data source, so layers created this way (rather
+    /// than imported from an external [`Dataset`]) have their features' ordinary `Feature`
+    /// fids assigned as network-global fids, which is what [`Network::connect_features`]
+    /// and the path queries expect.
+    ///
+    /// This wraps `GDALDatasetCreateLayer`.
+    pub fn create_layer(&self, name: &str, geom_type: OGRwkbGeometryType::Type) -> Result<Layer> {
+        let c_name = CString::new(name)?;
+        let c_layer = unsafe {
+            gdal_sys::GDALDatasetCreateLayer(
+                self.c_network,
+                c_name.as_ptr(),
+                ptr::null_mut(),
+                geom_type,
+                ptr::null_mut(),
+            )
+        };
+        if c_layer.is_null() {
+            return Err(_last_null_pointer_err("GDALDatasetCreateLayer"));
+        }
+        Ok(unsafe { Layer::from_c_layer(None, c_layer) })
+    }
+
+    /// Connects two features, identified by their network-global `src_fid` and `tgt_fid`,
+    /// into a graph edge, optionally via a `connector_fid` feature describing the edge
+    /// geometry, with the given `cost` and `inv_cost` (cost when traversed in reverse).
+    ///
+    /// These fids are only meaningful once the features' source layer has been registered
+    /// as a network class, e.g. by creating it through [`Network::create_layer`] rather
+    /// than in some unrelated external [`Dataset`]. There is no `LayerAccess` parameter
+    /// because the network itself, not any single layer, is the source of truth for which
+    /// fids exist.
+    ///
+    /// This wraps `GNMConnectPointsByFeaturesIDs`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_features(
+        &self,
+        src_fid: i64,
+        tgt_fid: i64,
+        connector_fid: i64,
+        cost: f64,
+        inv_cost: f64,
+        direction: GnmDirection,
+    ) -> Result<i64> {
+        let new_fid = unsafe {
+            gdal_sys::GNMConnectPointsByFeaturesIDs(
+                self.c_network,
+                src_fid,
+                tgt_fid,
+                connector_fid,
+                cost,
+                inv_cost,
+                direction as gdal_sys::GNMDirection::Type,
+            )
+        };
+        if new_fid == -1 {
+            return Err(_last_cpl_err(CPLErr::CE_Failure));
+        }
+        Ok(new_fid)
+    }
+
+    /// Removes a graph edge previously created by [`Network::connect_features`].
+    ///
+    /// This wraps `GNMDisconnectFeaturesIDs`.
+    pub fn disconnect(&self, src_fid: i64, tgt_fid: i64, connector_fid: i64) -> Result<()> {
+        let rv = unsafe {
+            gdal_sys::GNMDisconnectFeaturesIDs(self.c_network, src_fid, tgt_fid, connector_fid)
+        };
+        if rv != gdal_sys::OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "GNMDisconnectFeaturesIDs",
+            });
+        }
+        Ok(())
+    }
+
+    /// Loads the whole graph topology into memory, mirroring `GNMGenericNetwork`'s
+    /// `LoadGraph`. This must be called before running shortest-path or
+    /// connected-component queries against a freshly opened network.
+    ///
+    /// This wraps `GNMGenericNetworkLoadGraph`.
+    pub fn load_graph(&self) -> Result<()> {
+        let rv = unsafe { gdal_sys::GNMGenericNetworkLoadGraph(self.c_network) };
+        if rv != gdal_sys::OGRErr::OGRERR_NONE {
+            return Err(GdalError::OgrError {
+                err: rv,
+                method_name: "GNMGenericNetworkLoadGraph",
+            });
+        }
+        Ok(())
+    }
+
+    /// Computes the shortest path between `start_fid` and `end_fid`, returning the route
+    /// as a vector layer whose features are the edges of the path, in order.
+    ///
+    /// This wraps `GNMGenericNetworkGetPath` with `algorithm` set to Dijkstra.
+    pub fn shortest_path(&self, start_fid: i64, end_fid: i64) -> Result<ResultLayer> {
+        let c_layer = unsafe {
+            gdal_sys::GNMGenericNetworkGetPath(
+                self.c_network,
+                start_fid,
+                end_fid,
+                gdal_sys::GNMGraphAlgorithm::GATDijkstraShortestPath,
+                ptr::null_mut(),
+            )
+        };
+        self.result_layer(c_layer, "GNMGenericNetworkGetPath")
+    }
+
+    /// Computes the connected components of the network, returning them as a single
+    /// vector layer in which each feature carries a component identifier.
+    ///
+    /// This wraps `GNMGenericNetworkGetPath` with `algorithm` set to connected
+    /// components.
+    pub fn connected_components(&self) -> Result<ResultLayer> {
+        let c_layer = unsafe {
+            gdal_sys::GNMGenericNetworkGetPath(
+                self.c_network,
+                0,
+                0,
+                gdal_sys::GNMGraphAlgorithm::GATConnectedComponents,
+                ptr::null_mut(),
+            )
+        };
+        self.result_layer(c_layer, "GNMGenericNetworkGetPath")
+    }
+
+    fn result_layer(&self, c_layer: OGRLayerH, method_name: &'static str) -> Result<ResultLayer> {
+        if c_layer.is_null() {
+            return Err(_last_null_pointer_err(method_name));
+        }
+        Ok(ResultLayer {
+            layer: unsafe { Layer::from_c_layer(None, c_layer) },
+            c_network: self.c_network,
+        })
+    }
+}
+
+impl Drop for Network {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::GNMCloseNetwork(self.c_network) };
+    }
+}
+
+/// A vector [`Layer`] returned by [`Network::shortest_path`] or
+/// [`Network::connected_components`].
+///
+/// Unlike layers borrowed from a [`Dataset`], this one owns a GNM result set that must be
+/// released through the network that produced it (`GNMGenericNetworkReleaseResultSet`)
+/// rather than through the generic OGR layer/dataset release path, so it is wrapped here
+/// to do that automatically on drop instead of requiring callers to remember to call a
+/// matching `release` method.
+#[derive(Debug)]
+pub struct ResultLayer<'a> {
+    layer: Layer<'a>,
+    c_network: GNMNetworkH,
+}
+
+impl<'a> Deref for ResultLayer<'a> {
+    type Target = Layer<'a>;
+
+    fn deref(&self) -> &Layer<'a> {
+        &self.layer
+    }
+}
+
+impl<'a> Drop for ResultLayer<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            gdal_sys::GNMGenericNetworkReleaseResultSet(self.c_network, self.layer.c_layer())
+        };
+    }
+}
+
+/// Direction in which a graph edge connecting two features may be traversed.
+///
+/// Mirrors the `GNMDirection` constants: there is no third, reverse-only direction in the
+/// underlying C API, just single (forward-only) and double (bidirectional) edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GnmDirection {
+    /// The edge can only be traversed from source to target.
+    Single = gdal_sys::GNMDirection::GNM_EDGE_DIR_SINGLE as isize,
+    /// The edge can be traversed in both directions.
+    Double = gdal_sys::GNMDirection::GNM_EDGE_DIR_DOUBLE as isize,
+}
+
+/// Returns the name of the network, as given to [`Network::create`], if any.
+pub(crate) fn _network_name(c_network: GNMNetworkH) -> Option<String> {
+    let rv = unsafe { gdal_sys::GNMGetName(c_network) };
+    _string(rv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::{Defn, Feature, Geometry, LayerAccess};
+    use crate::Driver;
+
+    #[test]
+    fn connects_features_and_finds_shortest_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let ds = Driver::get("Memory")
+            .unwrap()
+            .create_vector_only("")
+            .unwrap();
+        let network = Network::create(&ds, dir.path().join("net.gnm"), "test_net", "").unwrap();
+
+        let layer = network
+            .create_layer("vertices", OGRwkbGeometryType::wkbPoint)
+            .unwrap();
+        let defn = Defn::from_layer(&layer);
+        let fids: Vec<i64> = (0..3)
+            .map(|i| {
+                let mut feature = Feature::new(&defn).unwrap();
+                feature
+                    .set_geometry(Geometry::from_wkt(&format!("POINT ({i} 0)")).unwrap())
+                    .unwrap();
+                feature.create(&layer).unwrap();
+                feature.fid().unwrap()
+            })
+            .collect();
+
+        network
+            .connect_features(fids[0], fids[1], -1, 1.0, 1.0, GnmDirection::Double)
+            .unwrap();
+        network
+            .connect_features(fids[1], fids[2], -1, 1.0, 1.0, GnmDirection::Double)
+            .unwrap();
+        network.load_graph().unwrap();
+
+        let route = network.shortest_path(fids[0], fids[2]).unwrap();
+        let edge_count = route.features().count();
+        assert_eq!(edge_count, 2);
+    }
+}
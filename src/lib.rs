@@ -0,0 +1,8 @@
+pub mod dataset;
+pub mod errors;
+pub mod gnm;
+pub mod spatial_ref;
+mod utils;
+pub mod vector;
+
+pub use dataset::{Dataset, Driver};
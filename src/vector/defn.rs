@@ -116,6 +116,86 @@ impl Defn {
         let idx = field_idx.try_into()?;
         Ok(idx)
     }
+
+    /// Get the index of the first field found among `candidates`.
+    ///
+    /// This is useful when the same attribute may be stored under different names
+    /// depending on the data source (e.g. `"nolanes"`, `"NOLANES"`, `"rnol"`), and the
+    /// caller wants to try each in turn rather than handling [`GdalError::InvalidFieldName`]
+    /// for every candidate individually.
+    ///
+    /// The comparison is done case-insensitively, as in [`Defn::field_index`].
+    /// If none of the candidates match, returns [`GdalError::InvalidFieldName`] listing all
+    /// of them.
+    pub fn field_index_any<S: AsRef<str>>(&self, candidates: &[S]) -> Result<usize> {
+        for candidate in candidates {
+            if let Ok(idx) = self._field_index(candidate.as_ref()) {
+                return Ok(idx);
+            }
+        }
+        Err(GdalError::InvalidFieldName {
+            field_name: Self::_join_candidates(candidates),
+            method_name: "OGR_FD_GetFieldIndex",
+        })
+    }
+
+    /// Get the index of the first geometry field found among `candidates`.
+    ///
+    /// See [`Defn::field_index_any`] for the rationale; this is the geometry-field
+    /// equivalent.
+    pub fn geometry_field_index_any<S: AsRef<str>>(&self, candidates: &[S]) -> Result<usize> {
+        for candidate in candidates {
+            if let Ok(idx) = self._geometry_field_index(candidate.as_ref()) {
+                return Ok(idx);
+            }
+        }
+        Err(GdalError::InvalidFieldName {
+            field_name: Self::_join_candidates(candidates),
+            method_name: "OGR_FD_GetGeomFieldIndex",
+        })
+    }
+
+    /// Returns whether a field named `field_name` exists.
+    ///
+    /// This lets callers probe for a field's presence without having to construct and
+    /// handle an [`GdalError::InvalidFieldName`] error.
+    pub fn has_field<S: AsRef<str>>(&self, field_name: S) -> bool {
+        self._field_index(field_name.as_ref()).is_ok()
+    }
+
+    fn _join_candidates<S: AsRef<str>>(candidates: &[S]) -> String {
+        candidates
+            .iter()
+            .map(|c| c.as_ref())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Appends `field_defn` to this layer's field schema.
+    ///
+    /// This wraps `OGR_FD_AddFieldDefn`, which copies `field_defn`'s definition, so the
+    /// [`FieldDefn`] passed in remains owned by (and is dropped by) the caller.
+    ///
+    /// This should only be used to define a layer's schema before any features have been
+    /// created against it: driver behavior when adding a field defn to a layer that
+    /// already has features is inconsistent, and can corrupt or lose data in existing
+    /// features.
+    pub fn add_field_defn(&self, field_defn: &FieldDefn) {
+        unsafe { gdal_sys::OGR_FD_AddFieldDefn(self.c_defn, field_defn.c_field_defn()) };
+    }
+
+    /// Appends `geom_field_defn` to this layer's geometry field schema.
+    ///
+    /// This wraps `OGR_FD_AddGeomFieldDefn`, which copies `geom_field_defn`'s definition,
+    /// so the [`GeomFieldDefn`] passed in remains owned by (and is dropped by) the caller.
+    ///
+    /// This should only be used to define a layer's schema before any features have been
+    /// created against it: driver behavior when adding a geometry field defn to a layer
+    /// that already has features is inconsistent, and can corrupt or lose data in existing
+    /// features.
+    pub fn add_geom_field_defn(&self, geom_field_defn: &GeomFieldDefn) {
+        unsafe { gdal_sys::OGR_FD_AddGeomFieldDefn(self.c_defn, geom_field_defn.c_field_defn()) };
+    }
 }
 
 pub struct FieldIterator<'a> {
@@ -249,3 +329,121 @@ impl<'a> GeomField<'a> {
         unsafe { SpatialRef::from_c_obj(c_obj) }
     }
 }
+
+/// An owned, mutable field definition, used to build up a field's schema before adding it
+/// to a [`Defn`] with [`Defn::add_field_defn`].
+///
+/// Wraps `OGR_Fld_Create`/`OGR_Fld_Destroy`, with setters mirroring every property
+/// readable through [`Field`].
+#[derive(Debug)]
+pub struct FieldDefn {
+    c_field_defn: OGRFieldDefnH,
+}
+
+impl FieldDefn {
+    /// Creates a new field definition named `name` with the given `field_type`.
+    pub fn new(name: &str, field_type: OGRFieldType::Type) -> Result<FieldDefn> {
+        let c_str_name = CString::new(name)?;
+        let c_field_defn = unsafe { gdal_sys::OGR_Fld_Create(c_str_name.as_ptr(), field_type) };
+        if c_field_defn.is_null() {
+            return Err(_last_null_pointer_err("OGR_Fld_Create"));
+        }
+        Ok(FieldDefn { c_field_defn })
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_field_defn(&self) -> OGRFieldDefnH {
+        self.c_field_defn
+    }
+
+    /// Set the formatting width of this field. Zero means no specified width.
+    pub fn set_width(&mut self, width: i32) {
+        unsafe { gdal_sys::OGR_Fld_SetWidth(self.c_field_defn, width) };
+    }
+
+    /// Set the formatting precision of this field. Should normally be zero for fields of
+    /// types other than Real.
+    pub fn set_precision(&mut self, precision: i32) {
+        unsafe { gdal_sys::OGR_Fld_SetPrecision(self.c_field_defn, precision) };
+    }
+
+    /// Set whether this field can receive null values.
+    pub fn set_nullable(&mut self, nullable: bool) {
+        unsafe { gdal_sys::OGR_Fld_SetNullable(self.c_field_defn, nullable as c_int) };
+    }
+
+    /// Set whether this field has a unique constraint.
+    pub fn set_unique(&mut self, unique: bool) {
+        unsafe { gdal_sys::OGR_Fld_SetUnique(self.c_field_defn, unique as c_int) };
+    }
+
+    /// Set the default value of this field, as an unparsed SQL literal (e.g. `"0"`,
+    /// `"'a default string'"`, `"CURRENT_TIMESTAMP"`).
+    pub fn set_default(&mut self, default: &str) -> Result<()> {
+        let c_str_default = CString::new(default)?;
+        unsafe { gdal_sys::OGR_Fld_SetDefault(self.c_field_defn, c_str_default.as_ptr()) };
+        Ok(())
+    }
+
+    /// Set the alternative name (alias) of this field.
+    pub fn set_alternative_name(&mut self, alternative_name: &str) -> Result<()> {
+        let c_str_name = CString::new(alternative_name)?;
+        unsafe { gdal_sys::OGR_Fld_SetAlternativeName(self.c_field_defn, c_str_name.as_ptr()) };
+        Ok(())
+    }
+}
+
+impl Drop for FieldDefn {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_Fld_Destroy(self.c_field_defn) };
+    }
+}
+
+/// An owned, mutable geometry field definition, used to build up a geometry field's
+/// schema before adding it to a [`Defn`] with [`Defn::add_geom_field_defn`].
+///
+/// Wraps `OGR_GFld_Create`/`OGR_GFld_Destroy`, with setters mirroring every property
+/// readable through [`GeomField`].
+#[derive(Debug)]
+pub struct GeomFieldDefn {
+    c_field_defn: OGRGeomFieldDefnH,
+}
+
+impl GeomFieldDefn {
+    /// Creates a new geometry field definition named `name` with the given `field_type`.
+    pub fn new(name: &str, field_type: OGRwkbGeometryType::Type) -> Result<GeomFieldDefn> {
+        let c_str_name = CString::new(name)?;
+        let c_field_defn = unsafe { gdal_sys::OGR_GFld_Create(c_str_name.as_ptr(), field_type) };
+        if c_field_defn.is_null() {
+            return Err(_last_null_pointer_err("OGR_GFld_Create"));
+        }
+        Ok(GeomFieldDefn { c_field_defn })
+    }
+
+    /// Returns the wrapped C pointer
+    ///
+    /// # Safety
+    /// This method returns a raw C pointer
+    pub unsafe fn c_field_defn(&self) -> OGRGeomFieldDefnH {
+        self.c_field_defn
+    }
+
+    /// Set whether this geometry field can receive null/empty geometries.
+    pub fn set_nullable(&mut self, nullable: bool) {
+        unsafe { gdal_sys::OGR_GFld_SetNullable(self.c_field_defn, nullable as c_int) };
+    }
+
+    /// Set the spatial reference system of this geometry field.
+    pub fn set_spatial_ref(&mut self, spatial_ref: &SpatialRef) {
+        unsafe { gdal_sys::OGR_GFld_SetSpatialRef(self.c_field_defn, spatial_ref.to_c_hsrs()) };
+    }
+}
+
+impl Drop for GeomFieldDefn {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::OGR_GFld_Destroy(self.c_field_defn) };
+    }
+}